@@ -136,6 +136,11 @@ fn main() {
 		move || { window.stop() }
 	});
 	
+	// NOTE chunk0-6 (Android native-activity entry point): not implemented. A real
+	// bootstrap needs the native-activity JNI entry point plus ANativeWindow/AssetManager
+	// handoff and touch-event mapping in App/Window themselves, which aren't source
+	// present in this tree (main.rs is the only file here)
+	
 	window.set_keyboard_pressed_callback({
 		let mut window = window.copy_ptr();
 		move |key: u32, _code: u32| {
@@ -163,10 +168,19 @@ fn main() {
 	// build info
 	ts_logf!(Message, "Build: {0}\n", App::build_info());
 	
+	// NOTE chunk0-5 (RenderDoc frame-capture control on Device): not implemented. A real
+	// integration needs is_debugger_attached()/debugger_name()/begin_capture()/end_capture()
+	// on Device itself, which has no implementation anywhere in this tree to add them to
+	
 	// create target
 	let mut target = device.create_target_with_window(&mut window);
 	if !target.is_valid_ptr() { exit(1) }
 	
+	// NOTE chunk0-1 (OpenXR stereo rendering path): not implemented. A real stereo path
+	// needs an XrSession type (HMD enumeration, per-eye view/projection math, compositor
+	// frame timing) that would live in the tellusim crate itself; that crate's source
+	// isn't in this tree, so there's nothing here to build the feature on top of
+	
 	////////////////////////////////
 	// core test
 	////////////////////////////////
@@ -208,6 +222,11 @@ fn main() {
 	let mut root = ControlRoot::new_with_canvas_blob(&mut canvas, true);
 	root.set_font_size(24);
 	
+	// NOTE chunk0-4 (AccessKit-style accessibility tree): not implemented. A real export
+	// needs a node tree with role/label/bounds/state plus focus-navigation callbacks on
+	// ControlRoot/Control, none of which exist in this tree's sole source file; building
+	// and forwarding that tree belongs in the tellusim crate itself
+	
 	// create rect
 	let mut rect = ControlRect::new_with_parent_mode(Some(&root.to_control()), CanvasElementMode::Texture);
 	rect.set_align(ControlAlign::Expand);
@@ -300,6 +319,11 @@ fn main() {
 	// render test
 	////////////////////////////////
 	
+	// NOTE chunk0-3 (GPU Hi-Z occlusion culling): not implemented. A real pass needs a
+	// depth pyramid build, per-object clip-space AABB projection, mip-level selection and
+	// near-plane/first-frame exemptions inside RenderSpatial::dispatch_objects, which lives
+	// in the tellusim crate; that source isn't in this tree
+	
 	// create render manager
 	let mut render_manager = RenderManager::new_with_manager(&mut scene_manager);
 	render_manager.set_draw_parameters_with_color_depth_multisample(&device, window.color_format(), window.depth_format(), window.multisample());
@@ -353,6 +377,11 @@ fn main() {
 	let mesh = create_mesh(&Vector2u::new(64, 32), &Vector2f::new(8.0, 2.0), 2.0);
 	object_mesh.create_with_mesh_material(&mesh, Some(&material));
 	
+	// NOTE chunk0-2 (glTF 2.0 importer): not implemented. A real importer needs node,
+	// mesh, material/texture, buffer view and index mapping plus extension support
+	// (e.g. KHR_materials_emissive_strength); that belongs in a SceneImporter type
+	// living in the tellusim crate, which this tree doesn't contain
+	
 	////////////////////////////////
 	// main loop
 	////////////////////////////////